@@ -0,0 +1,40 @@
+//! Benchmarks the per-flush cost of `TracingExporter::turn` at varying registry sizes, mirroring
+//! how the ecosystem benchmarks sync vs async exporter flush cost.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use metrics_core::Key;
+use metrics_exporter_tracing::{DefaultDrainBuilder, TracingExporter};
+use metrics_runtime::{Receiver, Sink};
+use std::time::Duration;
+
+fn seed(sink: &mut Sink, count: usize) {
+    for i in 0..count {
+        sink.increment_counter(Key::from_name(format!("metric_{}", i)), 1);
+    }
+}
+
+fn bench_turn(c: &mut Criterion) {
+    let mut group = c.benchmark_group("turn");
+
+    for &size in &[100usize, 10_000usize] {
+        let receiver = Receiver::builder().build().expect("failed to build receiver");
+        let mut sink = receiver.sink();
+        seed(&mut sink, size);
+
+        let mut exporter = TracingExporter::new(
+            receiver.controller(),
+            DefaultDrainBuilder(receiver.builder_type()),
+            tracing::Level::TRACE,
+            Duration::from_secs(1),
+        );
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| exporter.turn());
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_turn);
+criterion_main!(benches);
@@ -2,7 +2,15 @@
 //!
 //! This exporter can utilize observers that are able to be converted to a textual representation
 //! via [`Drain<String>`].  It will emit that output by logging via the `tracing` crate at the specified
-//! level.
+//! level. A single exporter can own more than one (observer, level) output via `add_output`, so one
+//! `turn()` can render, say, a compact summary at `INFO` alongside a verbose dump at `DEBUG`.
+//! Levels are plain runtime values behind a [`LevelHandle`], so they can be read or changed while
+//! the exporter is running rather than being fixed at construction time.
+//!
+//! Observers that instead expose their measurements as discrete records can be drained via
+//! [`StructuredDrain`] and logged with [`StructuredTracingExporter`], which emits one
+//! `tracing::event!` per metric with the name, value and labels attached as typed fields rather
+//! than flattened into a single message.
 //!
 //! # Run Modes
 //! - Using `run` will block the current thread, capturing a snapshot and logging it based on the
@@ -15,23 +23,196 @@ extern crate tracing;
 
 use tracing::Level;
 use metrics_core::{Builder, Drain, Observe, Observer};
-use std::{thread, time::Duration};
-use tokio::time;
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU8, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+use tokio::{sync::watch, time};
+
+/// The kind of a metric yielded by a [`StructuredDrain`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MetricKind {
+    /// A monotonically increasing counter.
+    Counter,
+    /// A point-in-time value that can go up or down.
+    Gauge,
+    /// A distribution of observed values.
+    Histogram,
+}
+
+/// The value carried by a single [`MetricRecord`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum MetricValue {
+    /// A floating-point measurement, as produced by gauges and histogram summaries.
+    Float(f64),
+    /// An integer measurement, as produced by counters.
+    Unsigned(u64),
+}
+
+/// A single metric measurement drained from an observer, along with its labels.
+#[derive(Debug, Clone)]
+pub struct MetricRecord {
+    /// The name of the metric.
+    pub name: String,
+    /// Whether this is a counter, gauge, or histogram measurement.
+    pub kind: MetricKind,
+    /// The measured value.
+    pub value: MetricValue,
+    /// The labels attached to this measurement.
+    pub labels: Vec<(String, String)>,
+}
+
+/// A drain that exposes its observed metrics as discrete, structured records rather than a
+/// single rendered string.
+///
+/// This is the structured counterpart to [`Drain<String>`]; implement it on an observer to
+/// make it usable with [`StructuredTracingExporter`].
+pub trait StructuredDrain {
+    /// Drains the observer, returning one [`MetricRecord`] per observed metric.
+    fn drain_structured(&mut self) -> Vec<MetricRecord>;
+}
+
+/// Renders its output into a caller-provided buffer instead of allocating a fresh `String` on
+/// every call.
+///
+/// This is the fast path used by [`TracingExporter::turn`] to avoid a per-flush allocation on
+/// registries with a large number of series. It deliberately has no blanket implementation for
+/// every `Drain<String>`: a blanket `impl<T: Drain<String>> DrainInto for T` would make it
+/// impossible for any concrete observer to ever provide a real override, since an inherent impl
+/// and a blanket impl for the same type conflict. Implement it directly on an observer to
+/// supply a real buffer-reusing override; wrap an observer that doesn't need one in
+/// [`DefaultDrain`] to get the default behavior, which still allocates a fresh `String` on
+/// every call.
+pub trait DrainInto {
+    /// Renders this drain's output into `buffer`.
+    fn drain_into(&mut self, buffer: &mut String);
+}
+
+/// Wraps any [`Drain<String>`] to give it the default [`DrainInto`] behavior, without requiring
+/// every `Drain<String>` to implement `DrainInto` itself (which would foreclose overriding it).
+pub struct DefaultDrain<T>(pub T);
+
+impl<T: Drain<String>> DrainInto for DefaultDrain<T> {
+    /// Falls back to [`Drain::drain`], replacing `buffer`'s contents with the freshly-allocated
+    /// `String` rather than copying into it. Implement [`DrainInto`] directly on the observer
+    /// itself to reuse `buffer`'s existing capacity and avoid the allocation entirely.
+    fn drain_into(&mut self, buffer: &mut String) {
+        *buffer = self.0.drain();
+    }
+}
+
+impl<T: Observer> Observer for DefaultDrain<T> {
+    fn observe_counter(&mut self, key: metrics_core::Key, value: u64) {
+        self.0.observe_counter(key, value);
+    }
+
+    fn observe_gauge(&mut self, key: metrics_core::Key, value: i64) {
+        self.0.observe_gauge(key, value);
+    }
+
+    fn observe_histogram(&mut self, key: metrics_core::Key, values: &[u64]) {
+        self.0.observe_histogram(key, values);
+    }
+}
+
+/// Wraps a [`Builder`] so its output is wrapped in [`DefaultDrain`], picking up the default
+/// [`DrainInto`] behavior for an observer that doesn't implement `DrainInto` itself.
+pub struct DefaultDrainBuilder<B>(pub B);
+
+impl<B: Builder> Builder for DefaultDrainBuilder<B> {
+    type Output = DefaultDrain<B::Output>;
+
+    fn build(&self) -> Self::Output {
+        DefaultDrain(self.0.build())
+    }
+}
+
+/// Dispatches to `tracing::event!` at a *runtime* [`Level`] value.
+///
+/// `tracing::event!`'s level argument must be a compile-time constant, since it is baked into a
+/// static `Metadata` per callsite; a bare variable (even one that happens to equal a constant)
+/// is rejected. This matches on the runtime value and calls `event!` with a literal `Level` in
+/// each arm so dynamically-configured levels (see [`LevelHandle`]) can still be emitted.
+macro_rules! emit_at_level {
+    ($level:expr, $($args:tt)*) => {
+        match $level {
+            Level::TRACE => tracing::event!(Level::TRACE, $($args)*),
+            Level::DEBUG => tracing::event!(Level::DEBUG, $($args)*),
+            Level::INFO => tracing::event!(Level::INFO, $($args)*),
+            Level::WARN => tracing::event!(Level::WARN, $($args)*),
+            Level::ERROR => tracing::event!(Level::ERROR, $($args)*),
+        }
+    };
+}
+
+fn level_to_u8(level: Level) -> u8 {
+    match level {
+        Level::TRACE => 0,
+        Level::DEBUG => 1,
+        Level::INFO => 2,
+        Level::WARN => 3,
+        Level::ERROR => 4,
+    }
+}
+
+fn level_from_u8(value: u8) -> Level {
+    match value {
+        0 => Level::TRACE,
+        1 => Level::DEBUG,
+        2 => Level::INFO,
+        3 => Level::WARN,
+        _ => Level::ERROR,
+    }
+}
+
+/// A cheaply-cloneable handle to a logging level that can be read or changed while its owning
+/// exporter is running, e.g. from another thread or after the exporter has been moved into a
+/// spawned task.
+#[derive(Clone)]
+pub struct LevelHandle(Arc<AtomicU8>);
+
+impl LevelHandle {
+    fn new(level: Level) -> Self {
+        LevelHandle(Arc::new(AtomicU8::new(level_to_u8(level))))
+    }
+
+    /// Returns the current level.
+    pub fn get(&self) -> Level {
+        level_from_u8(self.0.load(Ordering::Relaxed))
+    }
+
+    /// Sets the level that will be used starting with the next turn.
+    pub fn set(&self, level: Level) {
+        self.0.store(level_to_u8(level), Ordering::Relaxed);
+    }
+}
+
+/// A single rendering target owned by a [`TracingExporter`]: an observer, the level its output
+/// is logged at, and the reusable buffer it renders into.
+struct Output<O> {
+    observer: O,
+    level: LevelHandle,
+    buffer: String,
+}
 
 /// Exports metrics by converting them to a textual representation and logging them.
-pub struct TracingExporter<const L: Level, C, B>
+pub struct TracingExporter<C, B>
 where
     B: Builder,
 {
     controller: C,
-    observer: B::Output,
+    outputs: Vec<Output<B::Output>>,
     interval: Duration,
 }
 
-impl<const L: Level, C, B> TracingExporter<C, B>
+impl<C, B> TracingExporter<C, B>
 where
     B: Builder,
-    B::Output: Drain<String> + Observer,
+    B::Output: DrainInto + Observer,
     C: Observe,
 {
     /// Creates a new [`TracingExporter`] that logs at the configurable level.
@@ -40,36 +221,648 @@ where
     pub fn new(controller: C, builder: B, level: Level, interval: Duration) -> Self {
         TracingExporter {
             controller,
-            observer: builder.build(),
-            level,
+            outputs: vec![Output {
+                observer: builder.build(),
+                level: LevelHandle::new(level),
+                buffer: String::new(),
+            }],
             interval,
         }
     }
 
+    /// Adds another (observer, level) output to this exporter, so the same `turn()` also renders
+    /// and logs `builder`'s output at `level`.
+    ///
+    /// This is how a single exporter can, for example, log a compact summary at `INFO` and a
+    /// verbose per-metric dump at `DEBUG` in one `turn()`. The controller is observed once more
+    /// per output added this way, since `B::Output` isn't required to be [`Clone`].
+    pub fn add_output(mut self, builder: B, level: Level) -> Self {
+        self.outputs.push(Output {
+            observer: builder.build(),
+            level: LevelHandle::new(level),
+            buffer: String::new(),
+        });
+        self
+    }
+
+    /// Returns a handle that can be used to read or change the level of the `index`-th output
+    /// (in the order it was added, starting with the output given to [`TracingExporter::new`])
+    /// while the exporter is running, without reconstructing it.
+    ///
+    /// Returns `None` if there is no output at `index`.
+    pub fn level_handle(&self, index: usize) -> Option<LevelHandle> {
+        self.outputs.get(index).map(|output| output.level.clone())
+    }
+
     /// Runs this exporter on the current thread, logging output at the interval
-    /// given on construction.
-    pub fn run(&mut self) {
+    /// given on construction, until `stop` is set to `true`.
+    ///
+    /// Returns the number of flushes performed.
+    pub fn run(&mut self, stop: Arc<AtomicBool>) -> usize {
+        let mut flushes = 0;
+        while !stop.load(Ordering::Relaxed) {
+            thread::sleep(self.interval);
+
+            self.turn();
+            flushes += 1;
+        }
+        flushes
+    }
+
+    /// Run this exporter, logging output only once.
+    ///
+    /// Each output's observer is observed, rendered via [`DrainInto`] into its own buffer, and
+    /// logged at its own level. The buffer is kept between turns so a `DrainInto` implementation
+    /// can reuse its capacity; `turn` itself does not clear it, and [`DefaultDrain`] replaces it
+    /// with a freshly allocated `String` on every call. With a single output — the common
+    /// case, and the only one reachable without calling [`Self::add_output`] — the controller is
+    /// observed exactly once; adding further outputs observes the controller once per output,
+    /// which keeps `B::Output` usable without requiring it to be [`Clone`].
+    pub fn turn(&mut self) {
+        for output in &mut self.outputs {
+            self.controller.observe(&mut output.observer);
+            output.observer.drain_into(&mut output.buffer);
+            emit_at_level!(output.level.get(), "{}", output.buffer);
+        }
+    }
+
+    /// Converts this exporter into a future which logs output at the interval given on
+    /// construction, until `true` is sent on `shutdown`.
+    ///
+    /// A final `turn()` is performed once shutdown is observed, so no metrics observed before
+    /// shutdown are lost. Returns the number of flushes performed.
+    pub async fn async_run(mut self, mut shutdown: watch::Receiver<bool>) -> usize {
+        let mut interval = time::interval(self.interval);
+        let mut flushes = 0;
         loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    self.turn();
+                    flushes += 1;
+                }
+                changed = shutdown.changed() => {
+                    // A dropped sender (`changed` erroring) can never signal shutdown again
+                    // either, so treat it the same as an explicit `true`.
+                    if changed.is_err() || *shutdown.borrow() {
+                        self.turn();
+                        flushes += 1;
+                        break;
+                    }
+                }
+            }
+        }
+        flushes
+    }
+}
+
+/// Exports metrics by draining them into [`MetricRecord`]s and logging each one as its own
+/// structured `tracing` event, with the name, value and labels attached as fields instead of
+/// being flattened into a single message.
+pub struct StructuredTracingExporter<C, B>
+where
+    B: Builder,
+{
+    controller: C,
+    observer: B::Output,
+    interval: Duration,
+    level: LevelHandle,
+    filter: Option<Box<dyn Fn(&str, &[(&str, &str)]) -> bool + Send + Sync>>,
+    level_override: Option<Box<dyn Fn(&str) -> Option<Level> + Send + Sync>>,
+}
+
+impl<C, B> StructuredTracingExporter<C, B>
+where
+    B: Builder,
+    B::Output: StructuredDrain + Observer,
+    C: Observe,
+{
+    /// Creates a new [`StructuredTracingExporter`] that logs at the configurable level.
+    ///
+    /// Observers expose their output as discrete [`MetricRecord`]s rather than a single string.
+    pub fn new(controller: C, builder: B, level: Level, interval: Duration) -> Self {
+        StructuredTracingExporter {
+            controller,
+            observer: builder.build(),
+            interval,
+            level: LevelHandle::new(level),
+            filter: None,
+            level_override: None,
+        }
+    }
+
+    /// Returns a handle that can be used to read or change this exporter's default level while
+    /// it is running, without reconstructing it.
+    ///
+    /// Per-metric overrides installed via [`Self::with_level_override`] still take precedence
+    /// over this default.
+    pub fn level_handle(&self) -> LevelHandle {
+        self.level.clone()
+    }
+
+    /// Installs a predicate that decides whether a drained metric, identified by its name and
+    /// labels, should be emitted at all.
+    ///
+    /// Metrics for which the predicate returns `false` are dropped before reaching `tracing`,
+    /// which is useful for down-sampling high-cardinality series or dropping noisy metrics
+    /// entirely.
+    pub fn with_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&str, &[(&str, &str)]) -> bool + Send + Sync + 'static,
+    {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Installs a function that can override the [`Level`] a specific metric (by name) is
+    /// emitted at.
+    ///
+    /// Metrics for which this returns `None` fall back to the exporter's current default level
+    /// (see [`Self::level_handle`]), so,
+    /// e.g., an error-rate gauge can be bumped to `WARN` while everything else stays at the
+    /// default level.
+    pub fn with_level_override<F>(mut self, level_override: F) -> Self
+    where
+        F: Fn(&str) -> Option<Level> + Send + Sync + 'static,
+    {
+        self.level_override = Some(Box::new(level_override));
+        self
+    }
+
+    /// Runs this exporter on the current thread, logging output at the interval
+    /// given on construction, until `stop` is set to `true`.
+    ///
+    /// Returns the number of flushes performed.
+    pub fn run(&mut self, stop: Arc<AtomicBool>) -> usize {
+        let mut flushes = 0;
+        while !stop.load(Ordering::Relaxed) {
             thread::sleep(self.interval);
 
             self.turn();
+            flushes += 1;
         }
+        flushes
     }
 
     /// Run this exporter, logging output only once.
+    ///
+    /// Unlike [`TracingExporter::turn`], this emits one `tracing::event!` per drained metric,
+    /// with the metric name, kind, value, and labels attached as fields (`metric.name`,
+    /// `metric.kind`, `metric.value`, and `metric.labels`) instead of a single rendered message.
+    /// The labels are recorded together as one `Debug`-formatted `metric.labels` field, not as
+    /// one field per label key.
     pub fn turn(&mut self) {
         self.controller.observe(&mut self.observer);
-        let output = self.observer.drain();
-        tracing::event!(L, "{}", output);
+        for record in self.observer.drain_structured() {
+            let labels: Vec<(&str, &str)> = record
+                .labels
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.as_str()))
+                .collect();
+
+            if let Some(filter) = &self.filter {
+                if !filter(&record.name, &labels) {
+                    continue;
+                }
+            }
+
+            let level = self
+                .level_override
+                .as_ref()
+                .and_then(|level_override| level_override(&record.name))
+                .unwrap_or_else(|| self.level.get());
+
+            match record.value {
+                MetricValue::Float(value) => emit_at_level!(
+                    level,
+                    metric.name = %record.name,
+                    metric.kind = ?record.kind,
+                    metric.value = value,
+                    metric.labels = ?record.labels,
+                ),
+                MetricValue::Unsigned(value) => emit_at_level!(
+                    level,
+                    metric.name = %record.name,
+                    metric.kind = ?record.kind,
+                    metric.value = value,
+                    metric.labels = ?record.labels,
+                ),
+            }
+        }
     }
 
-    /// Converts this exporter into a future which logs output at the interval
-    /// given on construction.
-    pub async fn async_run(mut self) {
+    /// Converts this exporter into a future which logs output at the interval given on
+    /// construction, until `true` is sent on `shutdown`.
+    ///
+    /// A final `turn()` is performed once shutdown is observed, so no metrics observed before
+    /// shutdown are lost. Returns the number of flushes performed.
+    pub async fn async_run(mut self, mut shutdown: watch::Receiver<bool>) -> usize {
         let mut interval = time::interval(self.interval);
+        let mut flushes = 0;
         loop {
-            interval.tick().await;
-            self.turn();
+            tokio::select! {
+                _ = interval.tick() => {
+                    self.turn();
+                    flushes += 1;
+                }
+                changed = shutdown.changed() => {
+                    // A dropped sender (`changed` erroring) can never signal shutdown again
+                    // either, so treat it the same as an explicit `true`.
+                    if changed.is_err() || *shutdown.borrow() {
+                        self.turn();
+                        flushes += 1;
+                        break;
+                    }
+                }
+            }
+        }
+        flushes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::Subscriber;
+    use tracing_subscriber::layer::{Context, SubscriberExt};
+    use tracing_subscriber::Layer;
+
+    /// A controller stand-in that doesn't touch a real registry; tests seed the observer's
+    /// records directly instead, so `observe` has nothing to do.
+    #[derive(Clone)]
+    struct FakeController;
+
+    impl Observe for FakeController {
+        fn observe<O: Observer>(&self, _observer: &mut O) {}
+    }
+
+    #[derive(Clone, Default)]
+    struct FakeObserver {
+        records: Vec<MetricRecord>,
+    }
+
+    impl Observer for FakeObserver {
+        fn observe_counter(&mut self, _key: metrics_core::Key, _value: u64) {}
+        fn observe_gauge(&mut self, _key: metrics_core::Key, _value: i64) {}
+        fn observe_histogram(&mut self, _key: metrics_core::Key, _values: &[u64]) {}
+    }
+
+    impl StructuredDrain for FakeObserver {
+        fn drain_structured(&mut self) -> Vec<MetricRecord> {
+            std::mem::take(&mut self.records)
+        }
+    }
+
+    impl Drain<String> for FakeObserver {
+        fn drain(&mut self) -> String {
+            String::new()
+        }
+    }
+
+    struct FakeBuilder {
+        seed: Vec<MetricRecord>,
+    }
+
+    impl Builder for FakeBuilder {
+        type Output = FakeObserver;
+
+        fn build(&self) -> FakeObserver {
+            FakeObserver {
+                records: self.seed.clone(),
+            }
+        }
+    }
+
+    /// Captures the level and fields of every `tracing` event emitted while it's installed.
+    #[derive(Default)]
+    struct CapturingLayer {
+        events: Arc<Mutex<Vec<(Level, Vec<(String, String)>)>>>,
+    }
+
+    #[derive(Default)]
+    struct FieldCapture(Vec<(String, String)>);
+
+    impl Visit for FieldCapture {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0.push((field.name().to_string(), format!("{:?}", value)));
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for CapturingLayer {
+        fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+            let mut capture = FieldCapture::default();
+            event.record(&mut capture);
+            self.events
+                .lock()
+                .unwrap()
+                .push((*event.metadata().level(), capture.0));
         }
     }
+
+    #[test]
+    fn structured_turn_emits_one_event_per_metric_with_typed_fields() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let layer = CapturingLayer {
+            events: events.clone(),
+        };
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        let seed = vec![MetricRecord {
+            name: "requests_total".to_string(),
+            kind: MetricKind::Counter,
+            value: MetricValue::Unsigned(42),
+            labels: vec![("route".to_string(), "/health".to_string())],
+        }];
+
+        let mut exporter = StructuredTracingExporter::new(
+            FakeController,
+            FakeBuilder { seed },
+            Level::INFO,
+            Duration::from_secs(60),
+        );
+
+        tracing::subscriber::with_default(subscriber, || {
+            exporter.turn();
+        });
+
+        let captured = events.lock().unwrap();
+        assert_eq!(captured.len(), 1);
+        let (level, fields) = &captured[0];
+        assert_eq!(*level, Level::INFO);
+        assert!(fields
+            .iter()
+            .any(|(name, value)| name == "metric.name" && value.contains("requests_total")));
+        assert!(fields
+            .iter()
+            .any(|(name, value)| name == "metric.value" && value == "42"));
+    }
+
+    #[test]
+    fn structured_turn_skips_metrics_rejected_by_filter() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let layer = CapturingLayer {
+            events: events.clone(),
+        };
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        let seed = vec![MetricRecord {
+            name: "noisy_metric".to_string(),
+            kind: MetricKind::Gauge,
+            value: MetricValue::Float(1.0),
+            labels: vec![],
+        }];
+
+        let mut exporter = StructuredTracingExporter::new(
+            FakeController,
+            FakeBuilder { seed },
+            Level::INFO,
+            Duration::from_secs(60),
+        )
+        .with_filter(|name, _labels| name != "noisy_metric");
+
+        tracing::subscriber::with_default(subscriber, || {
+            exporter.turn();
+        });
+
+        assert!(events.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn structured_run_returns_the_number_of_flushes_performed_before_stopping() {
+        let mut exporter = StructuredTracingExporter::new(
+            FakeController,
+            FakeBuilder { seed: Vec::new() },
+            Level::TRACE,
+            Duration::from_millis(1),
+        );
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_writer = stop.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            stop_writer.store(true, Ordering::Relaxed);
+        });
+
+        let flushes = exporter.run(stop);
+        assert!(flushes >= 1);
+    }
+
+    #[tokio::test]
+    async fn structured_async_run_performs_a_final_turn_on_shutdown_and_reports_flush_count() {
+        let exporter = StructuredTracingExporter::new(
+            FakeController,
+            FakeBuilder { seed: Vec::new() },
+            Level::TRACE,
+            Duration::from_millis(1),
+        );
+
+        let (tx, rx) = watch::channel(false);
+        let handle = tokio::spawn(exporter.async_run(rx));
+
+        time::sleep(Duration::from_millis(10)).await;
+        tx.send(true).unwrap();
+
+        let flushes = handle.await.unwrap();
+        assert!(flushes >= 1);
+    }
+
+    /// A controller that counts how many times it was observed, so tests can tell whether
+    /// `turn()` re-observes per output.
+    struct CountingController {
+        observes: Arc<Mutex<usize>>,
+    }
+
+    impl Observe for CountingController {
+        fn observe<O: Observer>(&self, _observer: &mut O) {
+            *self.observes.lock().unwrap() += 1;
+        }
+    }
+
+    /// A plain `Drain<String>` observer (no manual `DrainInto` override), meant to be used via
+    /// [`DefaultDrain`] the way an observer this crate doesn't control would be.
+    struct FakeStringObserver {
+        drains: Arc<Mutex<usize>>,
+    }
+
+    impl Observer for FakeStringObserver {
+        fn observe_counter(&mut self, _key: metrics_core::Key, _value: u64) {}
+        fn observe_gauge(&mut self, _key: metrics_core::Key, _value: i64) {}
+        fn observe_histogram(&mut self, _key: metrics_core::Key, _values: &[u64]) {}
+    }
+
+    impl Drain<String> for FakeStringObserver {
+        fn drain(&mut self) -> String {
+            let mut drains = self.drains.lock().unwrap();
+            *drains += 1;
+            format!("flush {}", *drains)
+        }
+    }
+
+    struct FakeStringBuilder {
+        drains: Arc<Mutex<usize>>,
+    }
+
+    impl Builder for FakeStringBuilder {
+        type Output = DefaultDrain<FakeStringObserver>;
+
+        fn build(&self) -> Self::Output {
+            DefaultDrain(FakeStringObserver {
+                drains: self.drains.clone(),
+            })
+        }
+    }
+
+    #[test]
+    fn tracing_exporter_turn_observes_once_and_renders_the_default_drain_via_the_buffer() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let layer = CapturingLayer {
+            events: events.clone(),
+        };
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        let observes = Arc::new(Mutex::new(0));
+        let drains = Arc::new(Mutex::new(0));
+        let mut exporter = TracingExporter::new(
+            CountingController {
+                observes: observes.clone(),
+            },
+            FakeStringBuilder {
+                drains: drains.clone(),
+            },
+            Level::INFO,
+            Duration::from_secs(60),
+        );
+
+        tracing::subscriber::with_default(subscriber, || {
+            exporter.turn();
+            exporter.turn();
+        });
+
+        assert_eq!(*observes.lock().unwrap(), 2);
+        let captured = events.lock().unwrap();
+        assert_eq!(captured.len(), 2);
+        assert!(captured[0].1.iter().any(|(name, value)| name == "message"
+            && value.contains("flush 1")));
+        assert!(captured[1].1.iter().any(|(name, value)| name == "message"
+            && value.contains("flush 2")));
+    }
+
+    #[test]
+    fn tracing_exporter_add_output_logs_each_output_at_its_own_level() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let layer = CapturingLayer {
+            events: events.clone(),
+        };
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        let observes = Arc::new(Mutex::new(0));
+        let mut exporter = TracingExporter::new(
+            CountingController {
+                observes: observes.clone(),
+            },
+            FakeStringBuilder {
+                drains: Arc::new(Mutex::new(0)),
+            },
+            Level::INFO,
+            Duration::from_secs(60),
+        )
+        .add_output(
+            FakeStringBuilder {
+                drains: Arc::new(Mutex::new(0)),
+            },
+            Level::DEBUG,
+        );
+
+        tracing::subscriber::with_default(subscriber, || {
+            exporter.turn();
+        });
+
+        // One observe per output: adding a second output isn't free, but it keeps the observer
+        // type usable without requiring it to be `Clone`.
+        assert_eq!(*observes.lock().unwrap(), 2);
+
+        let captured = events.lock().unwrap();
+        let levels: Vec<Level> = captured.iter().map(|(level, _)| *level).collect();
+        assert_eq!(levels, vec![Level::INFO, Level::DEBUG]);
+    }
+
+    #[test]
+    fn tracing_exporter_level_handle_changes_the_level_used_by_the_next_turn() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let layer = CapturingLayer {
+            events: events.clone(),
+        };
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        let mut exporter = TracingExporter::new(
+            CountingController {
+                observes: Arc::new(Mutex::new(0)),
+            },
+            FakeStringBuilder {
+                drains: Arc::new(Mutex::new(0)),
+            },
+            Level::INFO,
+            Duration::from_secs(60),
+        );
+        let handle = exporter.level_handle(0).expect("output 0 exists");
+
+        tracing::subscriber::with_default(subscriber, || {
+            exporter.turn();
+            handle.set(Level::WARN);
+            exporter.turn();
+        });
+
+        let captured = events.lock().unwrap();
+        assert_eq!(captured[0].0, Level::INFO);
+        assert_eq!(captured[1].0, Level::WARN);
+    }
+
+    #[test]
+    fn tracing_exporter_run_returns_the_number_of_flushes_performed_before_stopping() {
+        let mut exporter = TracingExporter::new(
+            CountingController {
+                observes: Arc::new(Mutex::new(0)),
+            },
+            FakeStringBuilder {
+                drains: Arc::new(Mutex::new(0)),
+            },
+            Level::TRACE,
+            Duration::from_millis(1),
+        );
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_writer = stop.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            stop_writer.store(true, Ordering::Relaxed);
+        });
+
+        let flushes = exporter.run(stop);
+        assert!(flushes >= 1);
+    }
+
+    #[tokio::test]
+    async fn tracing_exporter_async_run_performs_a_final_turn_on_shutdown_and_reports_flush_count()
+    {
+        let exporter = TracingExporter::new(
+            CountingController {
+                observes: Arc::new(Mutex::new(0)),
+            },
+            FakeStringBuilder {
+                drains: Arc::new(Mutex::new(0)),
+            },
+            Level::TRACE,
+            Duration::from_millis(1),
+        );
+
+        let (tx, rx) = watch::channel(false);
+        let handle = tokio::spawn(exporter.async_run(rx));
+
+        time::sleep(Duration::from_millis(10)).await;
+        tx.send(true).unwrap();
+
+        let flushes = handle.await.unwrap();
+        assert!(flushes >= 1);
+    }
 }